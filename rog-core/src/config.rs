@@ -0,0 +1,241 @@
+use log::warn;
+use serde_derive::{Deserialize, Serialize};
+use std::fs::OpenOptions;
+use std::io::{Read, Write};
+
+static CONFIG_PATH: &str = "/etc/rogcore/rogcore.conf";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PerformanceSetting {
+    pub min_percentage: u8,
+    pub max_percentage: u8,
+    pub no_turbo: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ModePerformance {
+    pub normal: PerformanceSetting,
+    pub boost: PerformanceSetting,
+    pub silent: PerformanceSetting,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GpuPerformanceSetting {
+    pub power_cap_watts: u32,
+    pub max_sclk_mhz: u32,
+    pub fan_percent: u8,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ModeGpuPerformance {
+    pub normal: GpuPerformanceSetting,
+    pub boost: GpuPerformanceSetting,
+    pub silent: GpuPerformanceSetting,
+}
+
+/// A single `(temp_c, fan_percent)` breakpoint in a [`FanCurve`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct FanCurvePoint {
+    pub temp_c: u8,
+    pub fan_percent: u8,
+}
+
+/// A sorted set of temperature/duty breakpoints, linearly interpolated
+/// between the two bracketing points at runtime.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FanCurve {
+    pub enabled: bool,
+    pub poll_interval_ms: u64,
+    /// Minimum temperature change (in either direction) required before the
+    /// fan duty is recomputed, to stop small jitter thrashing the fan.
+    pub hysteresis_c: u8,
+    pub points: Vec<FanCurvePoint>,
+}
+
+impl Default for FanCurve {
+    fn default() -> Self {
+        FanCurve {
+            enabled: false,
+            poll_interval_ms: 2000,
+            hysteresis_c: 2,
+            points: vec![
+                FanCurvePoint {
+                    temp_c: 40,
+                    fan_percent: 0,
+                },
+                FanCurvePoint {
+                    temp_c: 55,
+                    fan_percent: 30,
+                },
+                FanCurvePoint {
+                    temp_c: 70,
+                    fan_percent: 60,
+                },
+                FanCurvePoint {
+                    temp_c: 85,
+                    fan_percent: 100,
+                },
+            ],
+        }
+    }
+}
+
+impl FanCurve {
+    /// Interpolate the fan duty percentage for `temp_c`, clamping to the
+    /// first/last breakpoints outside the curve's range.
+    pub fn interpolate(&self, temp_c: f32) -> f32 {
+        if self.points.is_empty() {
+            return 0.0;
+        }
+
+        let first = &self.points[0];
+        if temp_c <= first.temp_c as f32 {
+            return first.fan_percent as f32;
+        }
+
+        let last = &self.points[self.points.len() - 1];
+        if temp_c >= last.temp_c as f32 {
+            return last.fan_percent as f32;
+        }
+
+        for pair in self.points.windows(2) {
+            let (lo, hi) = (&pair[0], &pair[1]);
+            if temp_c >= lo.temp_c as f32 && temp_c <= hi.temp_c as f32 {
+                let lo_temp = lo.temp_c as f32;
+                let hi_temp = hi.temp_c as f32;
+                let lo_pct = lo.fan_percent as f32;
+                let hi_pct = hi.fan_percent as f32;
+                return lo_pct + (temp_c - lo_temp) * (hi_pct - lo_pct) / (hi_temp - lo_temp);
+            }
+        }
+
+        last.fan_percent as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interpolate_clamps_below_first_point() {
+        let curve = FanCurve::default();
+        assert_eq!(curve.interpolate(20.0), 0.0);
+    }
+
+    #[test]
+    fn interpolate_clamps_above_last_point() {
+        let curve = FanCurve::default();
+        assert_eq!(curve.interpolate(95.0), 100.0);
+    }
+
+    #[test]
+    fn interpolate_hits_breakpoints_exactly() {
+        let curve = FanCurve::default();
+        assert_eq!(curve.interpolate(55.0), 30.0);
+        assert_eq!(curve.interpolate(70.0), 60.0);
+    }
+
+    #[test]
+    fn interpolate_midpoint_between_breakpoints() {
+        let curve = FanCurve::default();
+        // Halfway between (55, 30) and (70, 60).
+        assert_eq!(curve.interpolate(62.5), 45.0);
+    }
+
+    #[test]
+    fn interpolate_empty_curve_is_zero() {
+        let curve = FanCurve {
+            enabled: true,
+            poll_interval_ms: 1000,
+            hysteresis_c: 0,
+            points: vec![],
+        };
+        assert_eq!(curve.interpolate(50.0), 0.0);
+    }
+}
+
+/// One row of the GPU power-limit-to-frequency scaling table: the GPU max
+/// core clock is capped at `gpu_max_freq_mhz` when this is the first row
+/// (in descending order) whose `power_limit_watts` is at or below the
+/// observed CPU package power limit.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PowerGpuEntry {
+    pub power_limit_watts: u32,
+    pub gpu_max_freq_mhz: u32,
+}
+
+/// A user-defined bundle of fan mode, CPU pstate, GPU, battery charge
+/// limit, and fan curve settings, applied together via
+/// `RogCore::set_profile`. The settings are applied one after another;
+/// nothing already applied is rolled back if a later step fails.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Profile {
+    pub name: String,
+    pub fan_mode: u8,
+    pub performance: PerformanceSetting,
+    #[serde(default)]
+    pub gpu: GpuPerformanceSetting,
+    pub bat_charge_limit: u8,
+    #[serde(default)]
+    pub fan_curve: FanCurve,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Config {
+    pub fan_mode: u8,
+    pub mode_performance: ModePerformance,
+    #[serde(default)]
+    pub mode_gpu: ModeGpuPerformance,
+    pub bat_charge_limit: u8,
+    #[serde(default)]
+    pub fan_curve: FanCurve,
+    /// User-defined named profiles. Empty by default: with no profiles
+    /// configured, `RogCore::next_profile` falls back to the built-in
+    /// numeric `fan_mode_step`.
+    #[serde(default)]
+    pub profiles: Vec<Profile>,
+    #[serde(default)]
+    pub active_profile: String,
+    /// Rows must be kept sorted descending by `power_limit_watts`; see
+    /// `RogCore::pick_gpu_freq_for_power`.
+    #[serde(default)]
+    pub gpu_scaling_table: Vec<PowerGpuEntry>,
+}
+
+impl Config {
+    pub fn load() -> Self {
+        let mut buf = String::new();
+        if let Ok(mut file) = OpenOptions::new().read(true).open(CONFIG_PATH) {
+            if file.read_to_string(&mut buf).is_ok() {
+                if let Ok(config) = toml::from_str(&buf) {
+                    return config;
+                }
+            }
+        }
+        warn!("Could not read config from {}, using defaults", CONFIG_PATH);
+        Config::default()
+    }
+
+    /// Re-read the config file from disk, in case it was edited externally.
+    pub fn read(&mut self) {
+        *self = Config::load();
+    }
+
+    /// Persist the current config to disk.
+    pub fn write(&self) {
+        if let Ok(data) = toml::to_string(self) {
+            if let Ok(mut file) = OpenOptions::new()
+                .write(true)
+                .create(true)
+                .truncate(true)
+                .open(CONFIG_PATH)
+            {
+                file.write_all(data.as_bytes())
+                    .unwrap_or_else(|err| warn!("Could not write {}, {:?}", CONFIG_PATH, err));
+            } else {
+                warn!("Could not open {} for writing", CONFIG_PATH);
+            }
+        }
+    }
+}
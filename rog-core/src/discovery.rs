@@ -0,0 +1,120 @@
+use log::{info, warn};
+use std::fs::{self, OpenOptions};
+use std::path::{Path, PathBuf};
+
+/// Walks a set of candidate parent directories under `/sys` looking for a
+/// writable `attribute` file, rather than trusting a single hardcoded index
+/// (e.g. `BAT0`, `hwmon0`, `policy0`) that may not be the right device on
+/// every board.
+///
+/// Some attributes sit directly in the candidate root (e.g. the global
+/// `cpufreq/boost` toggle), so that's tried first; others are nested one
+/// level down in a subdirectory whose name starts with `prefix` (e.g.
+/// `BAT0/charge_control_end_threshold`), which is tried next. Subdirectories
+/// are tried in sorted order so results are deterministic across runs on the
+/// same machine.
+pub fn discover_node(search_roots: &[&str], prefix: &str, attribute: &str) -> Option<PathBuf> {
+    for root in search_roots {
+        let root_path = Path::new(root);
+
+        let direct = root_path.join(attribute);
+        if is_writable(&direct) {
+            info!("Discovered {} at {}", attribute, direct.display());
+            return Some(direct);
+        }
+
+        let entries = match fs::read_dir(root_path) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+
+        let mut matches: Vec<PathBuf> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|n| n.to_str())
+                    .map(|n| n.starts_with(prefix))
+                    .unwrap_or(false)
+            })
+            .collect();
+        matches.sort();
+
+        for dir in matches {
+            let candidate = dir.join(attribute);
+            if is_writable(&candidate) {
+                info!("Discovered {} at {}", attribute, candidate.display());
+                return Some(candidate);
+            }
+        }
+    }
+
+    warn!(
+        "Could not discover a writable {} under {:?}",
+        attribute, search_roots
+    );
+    None
+}
+
+fn is_writable(path: &Path) -> bool {
+    OpenOptions::new().write(true).open(path).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    /// Every test gets its own directory under the OS temp dir, named after
+    /// the test so parallel runs don't collide.
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("rogcore-discovery-test-{}", name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn finds_attribute_nested_in_prefixed_subdir() {
+        let root = temp_dir("nested");
+        fs::create_dir_all(root.join("BAT0")).unwrap();
+        fs::write(root.join("BAT0/charge_control_end_threshold"), "100").unwrap();
+
+        let found = discover_node(
+            &[root.to_str().unwrap()],
+            "BAT",
+            "charge_control_end_threshold",
+        );
+        assert_eq!(found, Some(root.join("BAT0/charge_control_end_threshold")));
+    }
+
+    #[test]
+    fn finds_attribute_flat_in_root() {
+        let root = temp_dir("flat");
+        fs::write(root.join("boost"), "1").unwrap();
+
+        let found = discover_node(&[root.to_str().unwrap()], "policy", "boost");
+        assert_eq!(found, Some(root.join("boost")));
+    }
+
+    #[test]
+    fn returns_none_when_nothing_matches() {
+        let root = temp_dir("empty");
+
+        let found = discover_node(&[root.to_str().unwrap()], "policy", "boost");
+        assert_eq!(found, None);
+    }
+
+    #[test]
+    fn skips_nonexistent_root_and_tries_the_next() {
+        let root = temp_dir("second-root");
+        fs::write(root.join("boost"), "1").unwrap();
+
+        let found = discover_node(
+            &["/nonexistent/does/not/exist", root.to_str().unwrap()],
+            "policy",
+            "boost",
+        );
+        assert_eq!(found, Some(root.join("boost")));
+    }
+}
@@ -0,0 +1,28 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum RogError {
+    ParseFanLevel,
+    /// A GPU control node was never discovered (e.g. no amdgpu present).
+    GpuNodeNotFound(&'static str),
+    /// A GPU control node exists but could not be written to.
+    GpuWrite(String, String),
+    /// `RogCore::set_profile` was asked to apply a profile that doesn't
+    /// exist in `Config::profiles`.
+    ProfileNotFound(String),
+}
+
+impl fmt::Display for RogError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RogError::ParseFanLevel => write!(f, "could not parse fan level"),
+            RogError::GpuNodeNotFound(control) => {
+                write!(f, "GPU {} control is not available on this device", control)
+            }
+            RogError::GpuWrite(path, err) => write!(f, "could not write to {}: {}", path, err),
+            RogError::ProfileNotFound(name) => write!(f, "no profile named '{}'", name),
+        }
+    }
+}
+
+impl std::error::Error for RogError {}
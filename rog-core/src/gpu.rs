@@ -0,0 +1,152 @@
+use crate::config::GpuPerformanceSetting;
+use crate::error::RogError;
+use crate::pwm;
+use log::info;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Minimum gap enforced between the GPU max and min core clocks when
+/// raising the max clock, so power-limit scaling can never pin both rails
+/// to the same frequency.
+const GPU_FREQ_GUARD_MHZ: u32 = 200;
+const GPU_PWM_MIN: u32 = 0;
+const GPU_PWM_MAX: u32 = 255;
+
+/// Controls the discrete AMD GPU via the amdgpu sysfs interface: fan PWM,
+/// power cap, and clock/voltage state. Mirrors the CPU-side pstate control
+/// in `RogCore`, but every capability is optional since not all ROG laptops
+/// carry a dGPU, or expose all of these nodes.
+#[derive(Clone)]
+pub struct GpuControl {
+    pwm_path: Option<PathBuf>,
+    power_cap_path: Option<PathBuf>,
+    clk_voltage_path: Option<PathBuf>,
+}
+
+impl GpuControl {
+    pub fn new() -> Self {
+        let card = find_first_matching_dir("/sys/class/drm", "card");
+        let hwmon_dir = card.as_ref().and_then(|card| {
+            let hwmon_root = card.join("device/hwmon");
+            find_first_matching_dir(hwmon_root.to_str().unwrap_or_default(), "hwmon")
+        });
+
+        let pwm_path = hwmon_dir
+            .as_ref()
+            .map(|dir| dir.join("pwm1"))
+            .filter(|p| p.exists());
+        let power_cap_path = hwmon_dir
+            .as_ref()
+            .map(|dir| dir.join("power1_cap"))
+            .filter(|p| p.exists());
+        let clk_voltage_path = card
+            .as_ref()
+            .map(|dir| dir.join("device/pp_od_clk_voltage"))
+            .filter(|p| p.exists());
+
+        for (name, path) in [
+            ("fan PWM", &pwm_path),
+            ("power cap", &power_cap_path),
+            ("clock/voltage", &clk_voltage_path),
+        ] {
+            if let Some(path) = path {
+                info!("Discovered GPU {} at {}", name, path.display());
+            }
+        }
+
+        GpuControl {
+            pwm_path,
+            power_cap_path,
+            clk_voltage_path,
+        }
+    }
+
+    pub fn set_fan_pwm(&self, pwm: u32) -> Result<(), RogError> {
+        let path = self
+            .pwm_path
+            .as_deref()
+            .ok_or(RogError::GpuNodeNotFound("fan PWM"))?;
+        write_node(path, &pwm.to_string())
+    }
+
+    /// Convert a 0-100 fan duty percentage into the device's PWM range and
+    /// write it out. `pct` of 0 means fully off.
+    pub fn set_fan_percent(&self, pct: u8) -> Result<(), RogError> {
+        let pwm = pwm::percent_to_raw(pct as f32, GPU_PWM_MIN, GPU_PWM_MAX);
+        self.set_fan_pwm(pwm)
+    }
+
+    pub fn set_power_cap(&self, microwatts: u64) -> Result<(), RogError> {
+        let path = self
+            .power_cap_path
+            .as_deref()
+            .ok_or(RogError::GpuNodeNotFound("power cap"))?;
+        write_node(path, &microwatts.to_string())
+    }
+
+    /// Stage and commit a new GPU max core clock via `pp_od_clk_voltage`'s
+    /// small command language: `s 1 <mhz>` edits the top OD power state,
+    /// `c` commits the staged edit.
+    pub fn set_max_sclk(&self, mhz: u32) -> Result<(), RogError> {
+        let path = self
+            .clk_voltage_path
+            .as_deref()
+            .ok_or(RogError::GpuNodeNotFound("clock/voltage"))?;
+        write_node(path, &format!("s 1 {}\n", mhz))?;
+        write_node(path, "c\n")
+    }
+
+    /// Apply a bundled power cap + clock + fan duty target, as used by the
+    /// Silent/Normal/Boost fan modes.
+    pub fn apply_mode(&self, mode: &GpuPerformanceSetting) -> Result<(), RogError> {
+        self.set_power_cap(mode.power_cap_watts as u64 * 1_000_000)?;
+        self.set_max_sclk(mode.max_sclk_mhz)?;
+        self.set_fan_percent(mode.fan_percent)
+    }
+
+    /// Read the currently configured minimum GPU core clock (OD state 0)
+    /// out of `pp_od_clk_voltage`.
+    fn current_min_sclk_mhz(&self) -> Option<u32> {
+        let path = self.clk_voltage_path.as_deref()?;
+        let contents = std::fs::read_to_string(path).ok()?;
+        contents.lines().find_map(|line| {
+            let line = line.trim();
+            let rest = line.strip_prefix("0:")?;
+            rest.trim().trim_end_matches("Mhz").parse::<u32>().ok()
+        })
+    }
+
+    /// Set the GPU max core clock, clamping up to keep at least
+    /// `GPU_FREQ_GUARD_MHZ` above the current min clock so both rails are
+    /// never pinned to the same frequency.
+    pub fn set_max_sclk_with_guard(&self, mhz: u32) -> Result<(), RogError> {
+        let min = self.current_min_sclk_mhz().unwrap_or(0);
+        self.set_max_sclk(mhz.max(min + GPU_FREQ_GUARD_MHZ))
+    }
+}
+
+fn write_node(path: &Path, value: &str) -> Result<(), RogError> {
+    let mut file = OpenOptions::new()
+        .write(true)
+        .open(path)
+        .map_err(|err| RogError::GpuWrite(path.display().to_string(), err.to_string()))?;
+    file.write_all(value.as_bytes())
+        .map_err(|err| RogError::GpuWrite(path.display().to_string(), err.to_string()))
+}
+
+fn find_first_matching_dir(parent: &str, prefix: &str) -> Option<PathBuf> {
+    let entries = std::fs::read_dir(parent).ok()?;
+    let mut matches: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|n| n.to_str())
+                .map(|n| n.starts_with(prefix))
+                .unwrap_or(false)
+        })
+        .collect();
+    matches.sort();
+    matches.into_iter().next()
+}
@@ -0,0 +1,55 @@
+mod config;
+mod discovery;
+mod error;
+mod gpu;
+mod pwm;
+mod rogcore;
+
+use config::Config;
+use rogcore::{FanStatus, RogCore};
+use std::thread;
+use std::time::Duration;
+
+fn main() {
+    env_logger::init();
+
+    let mut config = Config::load();
+    let mut rogcore = RogCore::new(0, 0);
+
+    rogcore
+        .fan_mode_reload(&mut config)
+        .unwrap_or_else(|err| log::warn!("Could not reload fan mode: {:?}", err));
+
+    let fan_status = rogcore.start_fan_curve_daemon(&config);
+    rogcore.start_gpu_scaling_daemon(config);
+
+    // The fan-curve daemon only logs status transitions internally; this is
+    // the front-end this binary actually has, so surface anything a user
+    // should know about (stalls, implausible tacho readings) through the
+    // same log a desktop notifier or `journalctl` user would already be
+    // watching.
+    thread::spawn(move || {
+        let mut last_status = FanStatus::NotAvailable;
+        loop {
+            let status = *fan_status.lock().unwrap();
+            if status != last_status {
+                match status {
+                    FanStatus::Stalled => log::error!("Fan appears to be stalled"),
+                    FanStatus::LowSignal => {
+                        log::warn!("Fan tachometer signal is implausibly low")
+                    }
+                    FanStatus::Ok | FanStatus::NotAvailable => {}
+                }
+                last_status = status;
+            }
+            thread::sleep(Duration::from_secs(5));
+        }
+    });
+
+    // The daemons above only run on their own background threads; with
+    // nothing blocking here main() would return and take the whole process
+    // (and every un-joined thread) down with it.
+    loop {
+        thread::park();
+    }
+}
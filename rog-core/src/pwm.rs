@@ -0,0 +1,34 @@
+/// Convert a 0-100 duty percentage into a `min..=max` raw range (e.g. a PWM
+/// duty cycle), shared by both the CPU/chassis fan and the GPU fan. A `pct`
+/// of 0 maps to `min` (fully off); anything above that is spread across the
+/// rest of the range.
+pub fn percent_to_raw(pct: f32, min: u32, max: u32) -> u32 {
+    if pct <= 0.0 {
+        min
+    } else {
+        (((pct - 1.0) * max as f32 + (100.0 - pct) * min as f32) / 99.0) as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_percent_is_off() {
+        assert_eq!(percent_to_raw(0.0, 0, 255), 0);
+    }
+
+    #[test]
+    fn hundred_percent_is_max() {
+        assert_eq!(percent_to_raw(100.0, 0, 255), 255);
+    }
+
+    #[test]
+    fn one_percent_also_maps_to_min() {
+        // The formula's numerator collapses to `99 * min` at pct == 1, so
+        // this still lands on `min`, same as pct == 0 — there's no distinct
+        // "just above off" step until pct == 2.
+        assert_eq!(percent_to_raw(1.0, 0, 255), 0);
+    }
+}
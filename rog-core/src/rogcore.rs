@@ -1,18 +1,43 @@
 // Return show-stopping errors, otherwise map error to a log level
 
-use crate::{config::Config, error::RogError};
+use crate::discovery;
+use crate::gpu::GpuControl;
+use crate::pwm;
+use crate::{
+    config::{Config, FanCurve, PowerGpuEntry, Profile},
+    error::RogError,
+};
 use log::{error, info, warn};
 use std::error::Error;
 use std::fs::OpenOptions;
 use std::io::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
 
+/// Last-resort fallback paths for the fan mode, CPU boost, and battery
+/// charge limit nodes, used only if discovery can't find a live node under
+/// any candidate directory.
 static FAN_TYPE_1_PATH: &str = "/sys/devices/platform/asus-nb-wmi/throttle_thermal_policy";
 static FAN_TYPE_2_PATH: &str = "/sys/devices/platform/asus-nb-wmi/fan_boost_mode";
 static AMD_BOOST_PATH: &str = "/sys/devices/system/cpu/cpufreq/boost";
 static BAT_CHARGE_PATH: &str = "/sys/class/power_supply/BAT0/charge_control_end_threshold";
+/// Fixed hwmon and RAPL paths, used directly with no discovery.
+static HWMON_TEMP_PATH: &str = "/sys/class/hwmon/hwmon0/temp1_input";
+static HWMON_PWM_PATH: &str = "/sys/class/hwmon/hwmon0/pwm1";
+static HWMON_TACHO_PATH: &str = "/sys/class/hwmon/hwmon0/fan1_input";
+static RAPL_POWER_LIMIT_PATH: &str = "/sys/class/powercap/intel-rapl:0/constraint_0_power_limit_uw";
+static PWM_MIN: u32 = 0;
+static PWM_MAX: u32 = 255;
+/// RPM below this while a non-zero duty is commanded counts as stalled.
+static FAN_STALL_RPM: u32 = 100;
+/// RPM below this (but non-zero) is implausible for a spinning fan.
+static FAN_LOW_SIGNAL_RPM: u32 = 300;
+/// Consecutive low-RPM samples required before reporting a stall.
+static FAN_STALL_SAMPLES: u8 = 3;
 
 /// ROG device controller
 ///
@@ -23,45 +48,79 @@ static BAT_CHARGE_PATH: &str = "/sys/class/power_supply/BAT0/charge_control_end_
 /// - `LED_INIT4`
 /// - `LED_INIT2`
 /// - `LED_INIT4`
-pub struct RogCore {}
+pub struct RogCore {
+    fan_path: Option<PathBuf>,
+    boost_path: Option<PathBuf>,
+    charge_path: Option<PathBuf>,
+    gpu: GpuControl,
+    /// Shared with the GPU scaling daemon so toggling game mode takes
+    /// effect on its next poll without needing to restart it.
+    game_mode: Arc<Mutex<bool>>,
+    /// Shared with the fan curve daemon so switching profiles (or editing
+    /// the curve directly) takes effect on its next poll without needing
+    /// to restart it.
+    fan_curve: Arc<Mutex<FanCurve>>,
+}
 
 impl RogCore {
     pub fn new(vendor: u16, product: u16) -> Self {
-        RogCore {}
-    }
+        let fan_path = discovery::discover_node(
+            &["/sys/devices/platform"],
+            "asus-nb-wmi",
+            "throttle_thermal_policy",
+        )
+        .or_else(|| {
+            discovery::discover_node(&["/sys/devices/platform"], "asus-nb-wmi", "fan_boost_mode")
+        })
+        .or_else(|| Some(PathBuf::from(FAN_TYPE_1_PATH)).filter(|p| p.exists()))
+        .or_else(|| Some(PathBuf::from(FAN_TYPE_2_PATH)).filter(|p| p.exists()));
 
-    fn get_fan_path() -> Result<&'static str, std::io::Error> {
-        if Path::new(FAN_TYPE_1_PATH).exists() {
-            Ok(FAN_TYPE_1_PATH)
-        } else if Path::new(FAN_TYPE_2_PATH).exists() {
-            Ok(FAN_TYPE_2_PATH)
-        } else {
-            Err(std::io::Error::new(
-                std::io::ErrorKind::NotFound,
-                "Fan mode not available",
-            ))
+        let boost_path =
+            discovery::discover_node(&["/sys/devices/system/cpu/cpufreq"], "policy", "boost")
+                .or_else(|| Some(PathBuf::from(AMD_BOOST_PATH)).filter(|p| p.exists()));
+
+        let charge_path = discovery::discover_node(
+            &["/sys/class/power_supply"],
+            "BAT",
+            "charge_control_end_threshold",
+        )
+        .or_else(|| Some(PathBuf::from(BAT_CHARGE_PATH)).filter(|p| p.exists()));
+
+        RogCore {
+            fan_path,
+            boost_path,
+            charge_path,
+            gpu: GpuControl::new(),
+            game_mode: Arc::new(Mutex::new(false)),
+            fan_curve: Arc::new(Mutex::new(FanCurve::default())),
         }
     }
 
+    fn get_fan_path(&self) -> Result<&Path, std::io::Error> {
+        self.fan_path.as_deref().ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::NotFound, "Fan mode not available")
+        })
+    }
+
     pub fn fan_mode_reload(&mut self, config: &mut Config) -> Result<(), Box<dyn Error>> {
-        let path = RogCore::get_fan_path()?;
+        let path = self.get_fan_path()?;
         let mut file = OpenOptions::new().write(true).open(path)?;
         file.write_all(format!("{:?}\n", config.fan_mode).as_bytes())
-            .unwrap_or_else(|err| error!("Could not write to {}, {:?}", path, err));
+            .unwrap_or_else(|err| error!("Could not write to {:?}, {:?}", path, err));
         self.set_pstate_for_fan_mode(FanLevel::from(config.fan_mode), config)?;
         info!("Reloaded fan mode: {:?}", FanLevel::from(config.fan_mode));
         Ok(())
     }
 
     pub fn set_fan_mode(&mut self, n: u8, config: &mut Config) -> Result<(), Box<dyn Error>> {
-        let path = RogCore::get_fan_path()?;
+        let path = self.get_fan_path()?;
         let mut fan_ctrl = OpenOptions::new().read(true).write(true).open(path)?;
 
         config.fan_mode = n;
         config.write();
         fan_ctrl
             .write_all(format!("{:?}\n", config.fan_mode).as_bytes())
-            .unwrap_or_else(|err| error!("Could not write to {}, {:?}", path, err));
+            .unwrap_or_else(|err| error!("Could not write to {:?}, {:?}", path, err));
         info!("Fan mode set to: {:?}", FanLevel::from(config.fan_mode));
         self.set_pstate_for_fan_mode(FanLevel::from(n), config)?;
         Ok(())
@@ -81,6 +140,101 @@ impl RogCore {
         self.set_fan_mode(n, config)
     }
 
+    /// Apply a named profile's fan mode, pstate, GPU, charge limit, and fan
+    /// curve as one bundle, and persist it as the active profile.
+    pub fn set_profile(&mut self, name: &str, config: &mut Config) -> Result<(), Box<dyn Error>> {
+        let profile = config
+            .profiles
+            .iter()
+            .find(|p| p.name == name)
+            .cloned()
+            .ok_or_else(|| RogError::ProfileNotFound(name.to_string()))?;
+
+        self.apply_profile(&profile, config)
+    }
+
+    /// Cycle to the next user-defined profile, wrapping around. Falls back
+    /// to the built-in numeric `fan_mode_step` when no profiles are
+    /// configured, so existing setups keep working unchanged.
+    pub fn next_profile(&mut self, config: &mut Config) -> Result<(), Box<dyn Error>> {
+        config.read();
+
+        if config.profiles.is_empty() {
+            return self.fan_mode_step(config);
+        }
+
+        let next_index = config
+            .profiles
+            .iter()
+            .position(|p| p.name == config.active_profile)
+            .map_or(0, |idx| (idx + 1) % config.profiles.len());
+        let profile = config.profiles[next_index].clone();
+        self.apply_profile(&profile, config)
+    }
+
+    fn apply_profile(
+        &mut self,
+        profile: &Profile,
+        config: &mut Config,
+    ) -> Result<(), Box<dyn Error>> {
+        // Fan mode and charge limit are written best-effort, like the GPU
+        // step below: a missing node (e.g. no battery on a desktop-class
+        // board, or fan-path discovery coming up empty) is a legitimate,
+        // board-dependent outcome, not a reason to bail out of the rest of
+        // the bundle and leave the persisted config / running fan-curve
+        // daemon out of sync with whatever hardware state we did manage to
+        // apply.
+        match self.get_fan_path() {
+            Ok(path) => {
+                if let Err(err) = OpenOptions::new()
+                    .write(true)
+                    .open(path)
+                    .and_then(|mut file| {
+                        file.write_all(format!("{:?}\n", profile.fan_mode).as_bytes())
+                    })
+                {
+                    error!("Could not write to {:?}, {:?}", path, err);
+                }
+            }
+            Err(err) => warn!("Not applying fan mode for profile: {}", err),
+        }
+        config.fan_mode = profile.fan_mode;
+
+        if let Ok(pstate) = intel_pstate::PState::new() {
+            pstate.set_min_perf_pct(profile.performance.min_percentage)?;
+            pstate.set_max_perf_pct(profile.performance.max_percentage)?;
+            pstate.set_no_turbo(profile.performance.no_turbo)?;
+        } else if let Some(boost_path) = self.boost_path.clone() {
+            let boost = if profile.performance.no_turbo {
+                "0"
+            } else {
+                "1"
+            };
+            OpenOptions::new()
+                .write(true)
+                .open(&boost_path)?
+                .write_all(boost.as_bytes())
+                .unwrap_or_else(|err| error!("Could not write to {:?}, {:?}", boost_path, err));
+        }
+
+        self.gpu
+            .apply_mode(&profile.gpu)
+            .unwrap_or_else(|err| info!("Not applying GPU settings for profile: {}", err));
+
+        if let Err(err) = self.set_charge_limit(profile.bat_charge_limit, config) {
+            warn!("Not applying battery charge limit for profile: {}", err);
+        }
+
+        config.fan_curve = profile.fan_curve.clone();
+        config.active_profile = profile.name.clone();
+        config.write();
+
+        *self.fan_curve.lock().unwrap() = profile.fan_curve.clone();
+
+        info!("Applied profile: {}", profile.name);
+        Ok(())
+    }
+
     fn set_pstate_for_fan_mode(
         &self,
         mode: FanLevel,
@@ -126,13 +280,13 @@ impl RogCore {
         } else {
             info!("Setting pstate for AMD CPU");
             // must be AMD CPU
-            let mut file = OpenOptions::new()
-                .write(true)
-                .open(AMD_BOOST_PATH)
-                .map_err(|err| {
-                    warn!("Failed to open AMD boost: {:?}", err);
-                    err
-                })?;
+            let path = self.boost_path.as_deref().ok_or_else(|| {
+                std::io::Error::new(std::io::ErrorKind::NotFound, "AMD boost node not available")
+            })?;
+            let mut file = OpenOptions::new().write(true).open(path).map_err(|err| {
+                warn!("Failed to open AMD boost at {:?}: {:?}", path, err);
+                err
+            })?;
             match mode {
                 FanLevel::Normal => {
                     let boost = if config.mode_performance.normal.no_turbo {
@@ -140,9 +294,8 @@ impl RogCore {
                     } else {
                         "1"
                     }; // opposite of Intel
-                    file.write_all(boost.as_bytes()).unwrap_or_else(|err| {
-                        error!("Could not write to {}, {:?}", AMD_BOOST_PATH, err)
-                    });
+                    file.write_all(boost.as_bytes())
+                        .unwrap_or_else(|err| error!("Could not write to {:?}, {:?}", path, err));
                     info!("AMD CPU Turbo: {:?}", boost);
                 }
                 FanLevel::Boost => {
@@ -151,9 +304,8 @@ impl RogCore {
                     } else {
                         "1"
                     };
-                    file.write_all(boost.as_bytes()).unwrap_or_else(|err| {
-                        error!("Could not write to {}, {:?}", AMD_BOOST_PATH, err)
-                    });
+                    file.write_all(boost.as_bytes())
+                        .unwrap_or_else(|err| error!("Could not write to {:?}, {:?}", path, err));
                     info!("AMD CPU Turbo: {:?}", boost);
                 }
                 FanLevel::Silent => {
@@ -162,13 +314,22 @@ impl RogCore {
                     } else {
                         "1"
                     };
-                    file.write_all(boost.as_bytes()).unwrap_or_else(|err| {
-                        error!("Could not write to {}, {:?}", AMD_BOOST_PATH, err)
-                    });
+                    file.write_all(boost.as_bytes())
+                        .unwrap_or_else(|err| error!("Could not write to {:?}, {:?}", path, err));
                     info!("AMD CPU Turbo: {:?}", boost);
                 }
             }
         }
+
+        let gpu_setting = match mode {
+            FanLevel::Normal => &config.mode_gpu.normal,
+            FanLevel::Boost => &config.mode_gpu.boost,
+            FanLevel::Silent => &config.mode_gpu.silent,
+        };
+        self.gpu
+            .apply_mode(gpu_setting)
+            .unwrap_or_else(|err| info!("Not applying GPU mode: {}", err));
+
         Ok(())
     }
 
@@ -186,15 +347,21 @@ impl RogCore {
             );
         }
 
-        let mut file = OpenOptions::new()
-            .write(true)
-            .open(BAT_CHARGE_PATH)
-            .map_err(|err| {
-                warn!("Failed to open battery charge limit path: {:?}", err);
-                err
-            })?;
+        let path = self.charge_path.as_deref().ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "Battery charge limit node not available",
+            )
+        })?;
+        let mut file = OpenOptions::new().write(true).open(path).map_err(|err| {
+            warn!(
+                "Failed to open battery charge limit path {:?}: {:?}",
+                path, err
+            );
+            err
+        })?;
         file.write_all(limit.to_string().as_bytes())
-            .unwrap_or_else(|err| error!("Could not write to {}, {:?}", BAT_CHARGE_PATH, err));
+            .unwrap_or_else(|err| error!("Could not write to {:?}, {:?}", path, err));
         info!("Battery charge limit: {}", limit);
 
         config.bat_charge_limit = limit;
@@ -203,6 +370,221 @@ impl RogCore {
         Ok(())
     }
 
+    /// Spawn a background thread that polls CPU temperature and drives the
+    /// fan duty from the shared fan curve (seeded from `config.fan_curve`),
+    /// instead of the fixed firmware fan modes. The curve is re-read from
+    /// shared state on every tick, so `set_profile`/`apply_profile` can
+    /// swap it (or toggle it on/off) without restarting this daemon. Falls
+    /// back to leaving the fixed modes in control if no writable PWM node
+    /// is found. Returns a handle a front-end can poll to find out whether
+    /// the physical fan is actually spinning.
+    pub fn start_fan_curve_daemon(&self, config: &Config) -> Arc<Mutex<FanStatus>> {
+        let status = Arc::new(Mutex::new(FanStatus::NotAvailable));
+
+        *self.fan_curve.lock().unwrap() = config.fan_curve.clone();
+
+        if OpenOptions::new().write(true).open(HWMON_PWM_PATH).is_err() {
+            warn!(
+                "No writable PWM node at {}, falling back to fixed fan modes",
+                HWMON_PWM_PATH
+            );
+            return status;
+        }
+
+        let status_handle = Arc::clone(&status);
+        let curve_handle = Arc::clone(&self.fan_curve);
+        thread::spawn(move || {
+            let mut last_temp: Option<f32> = None;
+            let mut commanded_pct = 0.0;
+            let mut stall_samples = 0u8;
+            let mut last_status = FanStatus::NotAvailable;
+
+            loop {
+                let curve = curve_handle.lock().unwrap().clone();
+
+                if !curve.enabled {
+                    thread::sleep(Duration::from_millis(curve.poll_interval_ms));
+                    continue;
+                }
+
+                match RogCore::read_temp_c() {
+                    Ok(temp_c) => {
+                        let jittered = last_temp
+                            .map(|last| (temp_c - last).abs() < curve.hysteresis_c as f32)
+                            .unwrap_or(false);
+
+                        if !jittered {
+                            commanded_pct = curve.interpolate(temp_c);
+                            if let Err(err) = RogCore::write_pwm_for_percent(commanded_pct) {
+                                warn!("Could not write fan PWM: {:?}", err);
+                            }
+                            last_temp = Some(temp_c);
+                        }
+                    }
+                    Err(err) => warn!(
+                        "Could not read temperature from {}: {:?}",
+                        HWMON_TEMP_PATH, err
+                    ),
+                }
+
+                let new_status = match RogCore::read_fan_rpm() {
+                    None => FanStatus::NotAvailable,
+                    Some(rpm) if commanded_pct > 0.0 && rpm < FAN_STALL_RPM => {
+                        stall_samples = stall_samples.saturating_add(1);
+                        if stall_samples >= FAN_STALL_SAMPLES {
+                            FanStatus::Stalled
+                        } else {
+                            last_status
+                        }
+                    }
+                    Some(rpm) => {
+                        stall_samples = 0;
+                        if rpm > 0 && rpm < FAN_LOW_SIGNAL_RPM {
+                            FanStatus::LowSignal
+                        } else {
+                            FanStatus::Ok
+                        }
+                    }
+                };
+
+                if new_status != last_status {
+                    info!("Fan status changed: {:?} -> {:?}", last_status, new_status);
+                    last_status = new_status;
+                }
+                *status_handle.lock().unwrap() = new_status;
+
+                thread::sleep(Duration::from_millis(curve.poll_interval_ms));
+            }
+        });
+
+        status
+    }
+
+    fn read_temp_c() -> Result<f32, std::io::Error> {
+        let raw = std::fs::read_to_string(HWMON_TEMP_PATH)?;
+        let milli_c: f32 = raw.trim().parse().unwrap_or(0.0);
+        Ok(milli_c / 1000.0)
+    }
+
+    /// Read the tachometer sysfs node, returning `None` if no tacho node
+    /// exists for this fan.
+    fn read_fan_rpm() -> Option<u32> {
+        if !Path::new(HWMON_TACHO_PATH).exists() {
+            return None;
+        }
+        std::fs::read_to_string(HWMON_TACHO_PATH)
+            .ok()
+            .and_then(|raw| raw.trim().parse::<u32>().ok())
+    }
+
+    /// Convert a 0-100 fan duty percentage into the device's PWM range and
+    /// write it out. `pct` of 0 means fully off.
+    fn write_pwm_for_percent(pct: f32) -> Result<(), std::io::Error> {
+        let pwm = pwm::percent_to_raw(pct, PWM_MIN, PWM_MAX);
+
+        let mut file = OpenOptions::new().write(true).open(HWMON_PWM_PATH)?;
+        file.write_all(pwm.to_string().as_bytes())
+    }
+
+    /// Toggle game mode. On: apply the most aggressive row of
+    /// `config.gpu_scaling_table` and raise the CPU min-perf floor. Off:
+    /// restore the table-driven clock for the current CPU power limit and
+    /// let the scaling daemon resume driving it.
+    pub fn set_game_mode(&mut self, on: bool, config: &mut Config) -> Result<(), Box<dyn Error>> {
+        *self.game_mode.lock().unwrap() = on;
+
+        if on {
+            if let Some(entry) = config.gpu_scaling_table.first() {
+                self.gpu
+                    .set_max_sclk_with_guard(entry.gpu_max_freq_mhz)
+                    .unwrap_or_else(|err| warn!("Not applying game mode GPU clock: {}", err));
+            }
+            if let Ok(pstate) = intel_pstate::PState::new() {
+                pstate.set_min_perf_pct(100)?;
+            }
+            info!("Game mode enabled");
+        } else {
+            self.apply_gpu_scaling(config);
+            info!("Game mode disabled");
+        }
+
+        Ok(())
+    }
+
+    /// Pick the GPU max frequency for the first table row whose power
+    /// limit is at or below `power_limit_w`, clamping to the top row above
+    /// the table's max and the bottom row below its min. `table` must be
+    /// sorted descending by `power_limit_watts`.
+    fn pick_gpu_freq_for_power(table: &[PowerGpuEntry], power_limit_w: u32) -> Option<u32> {
+        table
+            .iter()
+            .find(|entry| power_limit_w >= entry.power_limit_watts)
+            .or_else(|| table.last())
+            .map(|entry| entry.gpu_max_freq_mhz)
+    }
+
+    fn read_cpu_power_limit_w() -> Result<u32, std::io::Error> {
+        let raw = std::fs::read_to_string(RAPL_POWER_LIMIT_PATH)?;
+        let microwatts: u64 = raw.trim().parse().unwrap_or(0);
+        Ok((microwatts / 1_000_000) as u32)
+    }
+
+    /// Read the current CPU package power limit and drive the GPU max
+    /// clock from `config.gpu_scaling_table`. No-op while game mode is on.
+    fn apply_gpu_scaling(&self, config: &Config) {
+        if *self.game_mode.lock().unwrap() || config.gpu_scaling_table.is_empty() {
+            return;
+        }
+
+        match RogCore::read_cpu_power_limit_w() {
+            Ok(power_w) => {
+                if let Some(freq) =
+                    RogCore::pick_gpu_freq_for_power(&config.gpu_scaling_table, power_w)
+                {
+                    self.gpu
+                        .set_max_sclk_with_guard(freq)
+                        .unwrap_or_else(|err| warn!("Not applying GPU scaling: {}", err));
+                }
+            }
+            Err(err) => warn!(
+                "Could not read CPU power limit from {}: {:?}",
+                RAPL_POWER_LIMIT_PATH, err
+            ),
+        }
+    }
+
+    /// Spawn a background thread that periodically re-applies the GPU
+    /// power-limit scaling table, deferring to game mode when it's on.
+    pub fn start_gpu_scaling_daemon(&self, config: Config) {
+        if config.gpu_scaling_table.is_empty() {
+            return;
+        }
+
+        let gpu = self.gpu.clone();
+        let game_mode = Arc::clone(&self.game_mode);
+
+        thread::spawn(move || loop {
+            if !*game_mode.lock().unwrap() {
+                match RogCore::read_cpu_power_limit_w() {
+                    Ok(power_w) => {
+                        if let Some(freq) =
+                            RogCore::pick_gpu_freq_for_power(&config.gpu_scaling_table, power_w)
+                        {
+                            gpu.set_max_sclk_with_guard(freq)
+                                .unwrap_or_else(|err| warn!("Not applying GPU scaling: {}", err));
+                        }
+                    }
+                    Err(err) => warn!(
+                        "Could not read CPU power limit from {}: {:?}",
+                        RAPL_POWER_LIMIT_PATH, err
+                    ),
+                }
+            }
+
+            thread::sleep(Duration::from_secs(2));
+        });
+    }
+
     /// A direct call to systemd to suspend the PC.
     ///
     /// This avoids desktop environments being required to handle it
@@ -254,7 +636,16 @@ impl RogCore {
     }
 }
 
-#[derive(Debug)]
+/// Health of a physical fan as inferred from tachometer readback.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FanStatus {
+    Ok,
+    NotAvailable,
+    Stalled,
+    LowSignal,
+}
+
+#[derive(Debug, Clone, Copy)]
 pub enum FanLevel {
     Normal,
     Boost,
@@ -294,3 +685,63 @@ impl From<FanLevel> for u8 {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scaling_table() -> Vec<PowerGpuEntry> {
+        vec![
+            PowerGpuEntry {
+                power_limit_watts: 80,
+                gpu_max_freq_mhz: 2200,
+            },
+            PowerGpuEntry {
+                power_limit_watts: 45,
+                gpu_max_freq_mhz: 1800,
+            },
+            PowerGpuEntry {
+                power_limit_watts: 25,
+                gpu_max_freq_mhz: 1200,
+            },
+        ]
+    }
+
+    #[test]
+    fn picks_exact_row_match() {
+        assert_eq!(
+            RogCore::pick_gpu_freq_for_power(&scaling_table(), 45),
+            Some(1800)
+        );
+    }
+
+    #[test]
+    fn picks_next_lower_row_between_breakpoints() {
+        // 60W isn't a row, but is still >= the 45W row's limit.
+        assert_eq!(
+            RogCore::pick_gpu_freq_for_power(&scaling_table(), 60),
+            Some(1800)
+        );
+    }
+
+    #[test]
+    fn clamps_to_top_row_above_table_max() {
+        assert_eq!(
+            RogCore::pick_gpu_freq_for_power(&scaling_table(), 150),
+            Some(2200)
+        );
+    }
+
+    #[test]
+    fn clamps_to_bottom_row_below_table_min() {
+        assert_eq!(
+            RogCore::pick_gpu_freq_for_power(&scaling_table(), 10),
+            Some(1200)
+        );
+    }
+
+    #[test]
+    fn empty_table_yields_no_frequency() {
+        assert_eq!(RogCore::pick_gpu_freq_for_power(&[], 45), None);
+    }
+}